@@ -1,5 +1,7 @@
 extern crate num;
 
+#[cfg(feature = "rand")] extern crate rand;
+
 #[cfg(test)] extern crate hamcrest;
 #[cfg(test)] extern crate quickcheck;
 
@@ -95,6 +97,39 @@ impl<T: Copy + Num + NumCast + PartialOrd> Angle<T> {
             Degrees(_) => Degrees(normalized)
         }
     }
+
+    /// Create a new angle by normalizing the value into the symmetric range
+    /// of `(-π, π]` rad / `(-180°, 180°]`, which is what most
+    /// heading-difference and steering-error computations expect.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use angular::*;
+    /// let alpha = Degrees(270.0f64).normalized_signed();
+    /// assert!((alpha.in_degrees() - -90.0).abs() < 1.0e-10);
+    ///
+    /// let beta = Degrees(180.0f64).normalized_signed();
+    /// assert!((beta.in_degrees() - 180.0).abs() < 1.0e-10);
+    /// ```
+    pub fn normalized_signed(self) -> Self {
+        let upper = match self {
+            Radians(_) => cast(2.0 * PI).unwrap(),
+            Degrees(_) => cast(360.0).unwrap()
+        };
+        let half = match self {
+            Radians(_) => cast(PI).unwrap(),
+            Degrees(_) => cast(180.0).unwrap()
+        };
+
+        let normalized = self.normalized();
+
+        match normalized {
+            Radians(v) if v > half => Radians(v - upper),
+            Degrees(v) if v > half => Degrees(v - upper),
+            other => other
+        }
+    }
 }
 
 impl<T: Float> Angle<T> {
@@ -122,6 +157,70 @@ impl<T: Float> Angle<T> {
             pi - ((d % two_pi) - pi).abs()
         })
     }
+
+    /// Linearly interpolate along the shortest arc between `self` and `other`,
+    /// where `t = 0` yields `self` and `t = 1` yields `other`.
+    ///
+    /// The interpolation always travels the short way around the circle. In
+    /// the antipodal case (the two angles are exactly π apart) the direction
+    /// is ambiguous, and this function picks the positive direction.
+    ///
+    /// ```rust
+    /// # use angular::*;
+    /// let mid = Degrees(0.0).lerp(Degrees(90.0), 0.5);
+    /// assert!((mid.in_degrees() - 45.0).abs() < 1.0e-10);
+    /// ```
+    pub fn lerp(self, other: Angle<T>, t: T) -> Angle<T> {
+        let two_pi: T = cast(2.0 * PI).unwrap();
+
+        let a = self.in_radians();
+        let b = other.in_radians();
+
+        let d = b - a;
+        let pi: T = cast(PI).unwrap();
+        let mut delta = d - two_pi * (d / two_pi).round();
+
+        // antipodal case: the direction is ambiguous, so pick the positive one.
+        if delta <= -pi {
+            delta = delta + two_pi;
+        }
+
+        Radians(a + delta * t).normalized()
+    }
+
+    /// Return the angle halfway along the shortest arc between `self` and
+    /// `other`.
+    ///
+    /// ```rust
+    /// # use angular::*;
+    /// let mid = Degrees(350.0).bisect(Degrees(10.0));
+    /// assert!(mid.in_degrees() < 1.0e-6 || (mid.in_degrees() - 360.0).abs() < 1.0e-6);
+    /// ```
+    pub fn bisect(self, other: Angle<T>) -> Angle<T> {
+        self.lerp(other, cast(0.5).unwrap())
+    }
+
+    /// Returns `true` if `self` and `other` are within `epsilon` of each
+    /// other, taking wrap-around into account (e.g. `Degrees(0.0)` and
+    /// `Degrees(360.0)` are `0.0` apart, not `360.0`).
+    ///
+    /// `epsilon` is always interpreted in radians, regardless of whether
+    /// `self` and `other` are `Radians` or `Degrees`.
+    ///
+    /// ```rust
+    /// # use angular::*;
+    /// assert!(Degrees(10.0).approx_eq(Degrees(10.0000001), 1.0e-6));
+    /// assert!(!Degrees(10.0).approx_eq(Degrees(20.0), 1.0e-6));
+    /// ```
+    pub fn approx_eq(self, other: Angle<T>, epsilon: T) -> bool {
+        self.min_dist(other).in_radians() <= epsilon
+    }
+
+    /// Returns `true` if `self` and `other` refer to the same angle once
+    /// both are normalized, e.g. `Degrees(0.0).equiv(Degrees(360.0))`.
+    pub fn equiv(self, other: Angle<T>) -> bool {
+        self.normalized().approx_eq(other.normalized(), cast(1.0e-10).unwrap())
+    }
 }
 
 impl<T: Signed> Angle<T> {
@@ -155,6 +254,69 @@ impl<T: Float + NumCast> Angle<T> {
     pub fn sin_cos(self) -> (T, T) {
         self.in_radians().sin_cos()
     }
+
+    /// Compute the cotangent of the angle.
+    pub fn cot(self) -> T {
+        self.tan().recip()
+    }
+
+    /// Compute the secant of the angle.
+    pub fn sec(self) -> T {
+        self.cos().recip()
+    }
+
+    /// Compute the cosecant of the angle.
+    pub fn csc(self) -> T {
+        self.sin().recip()
+    }
+
+    /// Compute the arcsine of a number. Return value is in the range of
+    /// [-π/2, π/2] rad or `None` if the number is outside the range [-1, 1].
+    pub fn asin(value: T) -> Option<Angle<T>> {
+        asin(value)
+    }
+
+    /// Compute the arccosine of a number. Return value is in the range of
+    /// [0, π] rad or `None` if the number is outside the range [-1, 1].
+    pub fn acos(value: T) -> Option<Angle<T>> {
+        acos(value)
+    }
+
+    /// Compute the arctangent of a number. Return value is in the range of
+    /// [-π/2, π/2] rad.
+    pub fn atan(value: T) -> Angle<T> {
+        atan(value)
+    }
+
+    /// Compute the four quadrant arctangent of `y` and `x`.
+    pub fn atan2(y: T, x: T) -> Angle<T> {
+        atan2(y, x)
+    }
+
+    /// Construct an angle from a 2D direction vector `(x, y)`, e.g. a heading
+    /// or slope. Equivalent to `atan2(y, x)`, normalized into `[0, 2π)`.
+    ///
+    /// ```rust
+    /// # use angular::*;
+    /// let heading = Angle::from_cartesian(0.0, 1.0);
+    /// assert!((heading.in_degrees() - 90.0).abs() < 1.0e-10);
+    /// ```
+    pub fn from_cartesian(x: T, y: T) -> Angle<T> {
+        atan2(y, x).normalized()
+    }
+
+    /// Return the unit vector `(cos, sin)` pointing in the direction of this
+    /// angle.
+    ///
+    /// ```rust
+    /// # use angular::*;
+    /// let (x, y) = Degrees(0.0f64).unit_vector();
+    /// assert!((x - 1.0).abs() < 1.0e-10 && y.abs() < 1.0e-10);
+    /// ```
+    pub fn unit_vector(self) -> (T, T) {
+        let (sin, cos) = self.sin_cos();
+        (cos, sin)
+    }
 }
 
 impl<T: Zero + Copy + NumCast> Zero for Angle<T> {
@@ -338,6 +500,34 @@ pub fn mean_angle<'a, T, I>(angles: I) -> Angle<T>
     Radians(a).normalized()
 }
 
+#[cfg(feature = "rand")]
+impl<T: Float + rand::Rand> rand::Rand for Angle<T> {
+    /// Sample a value uniformly distributed in `[0, 2π)` rad (or `[0, 360)`
+    /// deg, since both represent the full circle).
+    fn rand<R: rand::Rng>(rng: &mut R) -> Self {
+        let two_pi: T = cast(2.0 * PI).unwrap();
+        Radians(rng.gen::<T>() * two_pi)
+    }
+}
+
+/// Sample a uniformly distributed angle within the arbitrary range
+/// `[lo, hi]`, honoring wrap-around (e.g. a range from `Degrees(350.0)` to
+/// `Degrees(10.0)` samples through 0° rather than the long way round).
+#[cfg(feature = "rand")]
+pub fn sample_range<T, R>(rng: &mut R, lo: Angle<T>, hi: Angle<T>) -> Angle<T>
+    where T: Float, R: rand::Rng
+{
+    let two_pi: T = cast(2.0 * PI).unwrap();
+
+    let a = lo.in_radians();
+    let b = hi.in_radians();
+
+    let span = if b >= a { b - a } else { (b + two_pi) - a };
+    let t: T = cast(rng.gen::<f64>()).unwrap();
+
+    Radians(a + span * t).normalized()
+}
+
 
 // re-exports
 pub use Angle::{Radians, Degrees};
@@ -465,6 +655,24 @@ mod tests {
         quickcheck(prop as fn(Angle) -> bool);
     }
 
+    #[test]
+    fn test_angle_normalization_signed() {
+        fn prop(angle: Angle) -> bool {
+            let v = angle.normalized_signed();
+            let rad = v.in_radians();
+            let deg = v.in_degrees();
+
+            -PI < rad && rad <= PI &&
+            -180.0 < deg && deg <= 180.0 &&
+            are_close(rad.cos(), angle.cos())
+        }
+        quickcheck(prop as fn(Angle) -> bool);
+
+        assert_that(Degrees(270.0).normalized_signed().in_degrees(), is(close_to(-90.0, 0.000001)));
+        assert_that(Degrees(180.0).normalized_signed().in_degrees(), is(close_to(180.0, 0.000001)));
+        assert_that(Degrees(-270.0).normalized_signed().in_degrees(), is(close_to(90.0, 0.000001)));
+    }
+
     #[test]
     fn test_angle_minimal_distance() {
         fn prop(a: Angle, b: Angle) -> bool {
@@ -486,6 +694,67 @@ mod tests {
         assert_that(mean_angle(&[Degrees(20.0), Degrees(350.0)]).in_degrees(), is(close_to(5.0, 0.000001)));
     }
 
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_rand_sampling() {
+        use rand;
+
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..100 {
+            let angle: Angle = rand::Rand::rand(&mut rng);
+            let rad = angle.in_radians();
+            assert!(0.0 <= rad && rad < 2.0 * PI);
+
+            let sampled = sample_range(&mut rng, Degrees(350.0), Degrees(10.0));
+            let deg = sampled.in_degrees();
+            assert!(deg < 10.0 || deg >= 350.0);
+        }
+    }
+
+    #[test]
+    fn test_approx_eq_and_equiv() {
+        assert!(Degrees(10.0).approx_eq(Degrees(10.0000001), 1.0e-6));
+        assert!(!Degrees(10.0).approx_eq(Degrees(20.0), 1.0e-6));
+
+        assert!(Degrees(0.0).equiv(Degrees(360.0)));
+        assert!(!Degrees(0.0).equiv(Degrees(1.0)));
+    }
+
+    #[test]
+    fn test_cartesian_interop() {
+        assert_that(Angle::from_cartesian(1.0, 1.0).in_degrees(), is(close_to(45.0, 0.000001)));
+
+        let (x, y) = Degrees(90.0).unit_vector();
+        assert!(are_close(x, 0.0));
+        assert_that(y, is(close_to(1.0, 0.000001)));
+    }
+
+    #[test]
+    fn test_lerp_and_bisect() {
+        assert_that(Degrees(0.0).lerp(Degrees(90.0), 0.5).in_degrees(), is(close_to(45.0, 0.000001)));
+        assert_that(Degrees(0.0).bisect(Degrees(90.0)).in_degrees(), is(close_to(45.0, 0.000001)));
+
+        // shortest arc: going from 350° to 10° is a 20° arc through 0°, not
+        // the long way round through 180°.
+        assert_that(Degrees(350.0).lerp(Degrees(10.0), 0.5).in_degrees(), is(close_to(0.0, 0.000001)));
+    }
+
+    #[test]
+    fn test_reciprocal_trig() {
+        assert_that(Degrees(45.0).cot(), is(close_to(1.0, 0.000001)));
+        assert_that(Degrees(60.0).sec(), is(close_to(2.0, 0.000001)));
+        assert_that(Degrees(30.0).csc(), is(close_to(2.0, 0.000001)));
+    }
+
+    #[test]
+    fn test_inverse_trig_associated_functions() {
+        assert_that(Angle::asin(1.0).unwrap().in_degrees(), is(close_to(90.0, 0.000001)));
+        assert_that(Angle::acos(1.0).unwrap().in_degrees(), is(close_to(0.0, 0.000001)));
+        assert_that(Angle::atan(1.0).in_degrees(), is(close_to(45.0, 0.000001)));
+        assert_that(Angle::atan2(1.0, 1.0).in_degrees(), is(close_to(45.0, 0.000001)));
+    }
+
     fn are_close<T: Float>(a: T, b: T) -> bool {
         (a - b).abs() < cast(1.0e-10).unwrap()
     }